@@ -220,6 +220,16 @@
 //! default-features = false
 //! features = ["std"]
 //! ```
+//!
+//! # Runtime configuration
+//!
+//! By default, spawned tasks are run on one executor thread per logical CPU.
+//! Setting the `ASYNC_STD_THREAD_COUNT` environment variable to a positive
+//! number overrides this; it is read once, when the first task is spawned.
+//!
+//! ```sh
+//! $ ASYNC_STD_THREAD_COUNT=2 cargo run
+//! ```
 
 #![cfg_attr(feature = "docs", feature(doc_cfg))]
 #![warn(missing_docs, missing_debug_implementations, rust_2018_idioms)]
@@ -65,6 +65,12 @@ impl Reactor {
         let vacant = entries.vacant_entry();
         let token = mio::Token(vacant.key());
 
+        // Register the I/O event source in the poller. This happens before the slab entry is
+        // filled so that a failed registration doesn't leave a dead entry behind.
+        let interest = mio::Ready::all();
+        let opts = mio::PollOpt::edge();
+        self.poller.register(source, token, interest, opts)?;
+
         // Allocate an entry and insert it into the slab.
         let entry = Arc::new(Entry {
             token,
@@ -73,11 +79,6 @@ impl Reactor {
         });
         vacant.insert(entry.clone());
 
-        // Register the I/O event source in the poller.
-        let interest = mio::Ready::all();
-        let opts = mio::PollOpt::edge();
-        self.poller.register(source, token, interest, opts)?;
-
         Ok(entry)
     }
 
@@ -178,13 +179,22 @@ impl<T: Evented> Watcher<T> {
     ///
     /// The provided I/O event source will be kept registered inside the reactor's poller for the
     /// lifetime of the returned I/O handle.
+    ///
+    /// Panics if the I/O event source cannot be registered. Use `try_new` where the error can be
+    /// returned to the caller instead.
     pub fn new(source: T) -> Watcher<T> {
-        Watcher {
-            entry: REACTOR
-                .register(&source)
-                .expect("cannot register an I/O event source"),
+        Watcher::try_new(source).expect("cannot register an I/O event source")
+    }
+
+    /// Creates a new I/O handle, or returns an error if the reactor cannot register the source.
+    ///
+    /// A failed registration (for example when the poller runs out of resources) is reported as
+    /// an I/O error rather than bringing down the whole program.
+    pub fn try_new(source: T) -> io::Result<Watcher<T>> {
+        Ok(Watcher {
+            entry: REACTOR.register(&source)?,
             source: Some(source),
-        }
+        })
     }
 
     /// Returns a reference to the inner I/O event source.
@@ -313,3 +323,59 @@ fn hup() -> mio::Ready {
 
     ready
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An event source the poller refuses to register, as if the process ran out of descriptors.
+    #[derive(Debug)]
+    struct Exhausted;
+
+    impl Evented for Exhausted {
+        fn register(
+            &self,
+            _: &mio::Poll,
+            _: mio::Token,
+            _: mio::Ready,
+            _: mio::PollOpt,
+        ) -> io::Result<()> {
+            Err(io::Error::from_raw_os_error(libc::EMFILE))
+        }
+
+        fn reregister(
+            &self,
+            poll: &mio::Poll,
+            token: mio::Token,
+            interest: mio::Ready,
+            opts: mio::PollOpt,
+        ) -> io::Result<()> {
+            self.register(poll, token, interest, opts)
+        }
+
+        fn deregister(&self, _: &mio::Poll) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn udp_socket() -> mio::net::UdpSocket {
+        mio::net::UdpSocket::bind(&"127.0.0.1:0".parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn try_new_reports_registration_errors() {
+        let before = Watcher::try_new(udp_socket()).unwrap();
+        let entries = REACTOR.entries.lock().unwrap().len();
+
+        let err = Watcher::try_new(Exhausted).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(libc::EMFILE));
+
+        // The failed registration must not leak an entry, and the reactor keeps working.
+        let leftover = REACTOR.entries.lock().unwrap().len();
+        assert_eq!(leftover, entries);
+        let after = Watcher::try_new(udp_socket()).unwrap();
+
+        drop(before);
+        drop(after);
+    }
+}
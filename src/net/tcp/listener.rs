@@ -83,7 +83,7 @@ impl TcpListener {
             match mio::net::TcpListener::bind(&addr) {
                 Ok(mio_listener) => {
                     return Ok(TcpListener {
-                        watcher: Watcher::new(mio_listener),
+                        watcher: Watcher::try_new(mio_listener)?,
                     });
                 }
                 Err(err) => last_err = Some(err),
@@ -121,7 +121,7 @@ impl TcpListener {
 
         let mio_stream = mio::net::TcpStream::from_stream(io)?;
         let stream = TcpStream {
-            watcher: Watcher::new(mio_stream),
+            watcher: Watcher::try_new(mio_stream)?,
         };
         Ok((stream, addr))
     }
@@ -83,7 +83,8 @@ impl TcpStream {
                 let mio_stream = mio::net::TcpStream::from_stream(std_stream)
                     .context(|| format!("could not open async connection to {}", addr))?;
                 Ok(TcpStream {
-                    watcher: Watcher::new(mio_stream),
+                    watcher: Watcher::try_new(mio_stream)
+                        .context(|| format!("could not open async connection to {}", addr))?,
                 })
             })
             .await;
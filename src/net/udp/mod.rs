@@ -77,7 +77,7 @@ impl UdpSocket {
             match mio::net::UdpSocket::bind(&addr) {
                 Ok(mio_socket) => {
                     return Ok(UdpSocket {
-                        watcher: Watcher::new(mio_socket),
+                        watcher: Watcher::try_new(mio_socket)?,
                     });
                 }
                 Err(err) => last_err = Some(err),
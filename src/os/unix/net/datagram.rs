@@ -46,10 +46,10 @@ pub struct UnixDatagram {
 }
 
 impl UnixDatagram {
-    fn new(socket: mio_uds::UnixDatagram) -> UnixDatagram {
-        UnixDatagram {
-            watcher: Watcher::new(socket),
-        }
+    fn new(socket: mio_uds::UnixDatagram) -> io::Result<UnixDatagram> {
+        Ok(UnixDatagram {
+            watcher: Watcher::try_new(socket)?,
+        })
     }
 
     /// Creates a Unix datagram socket bound to the given path.
@@ -68,7 +68,7 @@ impl UnixDatagram {
     pub async fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixDatagram> {
         let path = path.as_ref().to_owned();
         let socket = spawn_blocking(move || mio_uds::UnixDatagram::bind(path)).await?;
-        Ok(UnixDatagram::new(socket))
+        UnixDatagram::new(socket)
     }
 
     /// Creates a Unix datagram which is not bound to any address.
@@ -86,7 +86,7 @@ impl UnixDatagram {
     /// ```
     pub fn unbound() -> io::Result<UnixDatagram> {
         let socket = mio_uds::UnixDatagram::unbound()?;
-        Ok(UnixDatagram::new(socket))
+        UnixDatagram::new(socket)
     }
 
     /// Creates an unnamed pair of connected sockets.
@@ -106,8 +106,8 @@ impl UnixDatagram {
     /// ```
     pub fn pair() -> io::Result<(UnixDatagram, UnixDatagram)> {
         let (a, b) = mio_uds::UnixDatagram::pair()?;
-        let a = UnixDatagram::new(a);
-        let b = UnixDatagram::new(b);
+        let a = UnixDatagram::new(a)?;
+        let b = UnixDatagram::new(b)?;
         Ok((a, b))
     }
 
@@ -72,7 +72,7 @@ impl UnixListener {
         let listener = spawn_blocking(move || mio_uds::UnixListener::bind(path)).await?;
 
         Ok(UnixListener {
-            watcher: Watcher::new(listener),
+            watcher: Watcher::try_new(listener)?,
         })
     }
 
@@ -107,7 +107,7 @@ impl UnixListener {
                 Some((io, addr)) => {
                     let mio_stream = mio_uds::UnixStream::from_stream(io)?;
                     let stream = UnixStream {
-                        watcher: Watcher::new(mio_stream),
+                        watcher: Watcher::try_new(mio_stream)?,
                     };
                     Poll::Ready(Ok((stream, addr)))
                 }
@@ -62,7 +62,7 @@ impl UnixStream {
             let std_stream = std::os::unix::net::UnixStream::connect(path)?;
             let mio_stream = mio_uds::UnixStream::from_stream(std_stream)?;
             Ok(UnixStream {
-                watcher: Watcher::new(mio_stream),
+                watcher: Watcher::try_new(mio_stream)?,
             })
         })
         .await
@@ -86,10 +86,10 @@ impl UnixStream {
     pub fn pair() -> io::Result<(UnixStream, UnixStream)> {
         let (a, b) = mio_uds::UnixStream::pair()?;
         let a = UnixStream {
-            watcher: Watcher::new(a),
+            watcher: Watcher::try_new(a)?,
         };
         let b = UnixStream {
-            watcher: Watcher::new(b),
+            watcher: Watcher::try_new(b)?,
         };
         Ok((a, b))
     }
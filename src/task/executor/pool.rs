@@ -1,4 +1,5 @@
 use std::cell::Cell;
+use std::env;
 use std::iter;
 use std::thread;
 use std::time::Duration;
@@ -25,7 +26,13 @@ struct Pool {
 
 /// Global executor that runs spawned tasks.
 static POOL: Lazy<Pool> = Lazy::new(|| {
-    let num_threads = num_cpus::get().max(1);
+    // Use the thread count from `ASYNC_STD_THREAD_COUNT` if it's set to a positive number, and
+    // fall back to the number of CPUs otherwise.
+    let num_threads = env::var("ASYNC_STD_THREAD_COUNT")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or_else(|| num_cpus::get().max(1));
     let mut stealers = Vec::new();
 
     // Spawn worker threads.
@@ -133,6 +140,8 @@ fn main_loop() {
 /// Find the next runnable task.
 fn find_runnable() -> Option<Runnable> {
     /// Maximum number of times the slot can be used in a row.
+    ///
+    /// `tests/yield_fairness.rs` keeps a copy of this value; update it there too.
     const SLOT_LIMIT: u32 = 16;
 
     PROCESSOR.with(|proc| {
@@ -145,6 +154,11 @@ fn find_runnable() -> Option<Runnable> {
                 proc.slot_runs.set(runs + 1);
                 return Some(task);
             }
+        } else if let Some(task) = proc.slot.take() {
+            // The slot was used too many times in a row, so move its task to the back of the local
+            // queue to give other tasks a turn.
+            proc.worker.push(task);
+            POOL.sleepers.notify_one();
         }
         proc.slot_runs.set(0);
 
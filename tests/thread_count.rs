@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+use std::env;
+use std::thread;
+
+use async_std::task;
+
+// This is the only test in this file because the executor reads the variable once, when the first
+// task is spawned. The default is one thread per CPU, so on a single-core machine this passes even
+// if the variable is ignored; it only catches that on multi-core machines.
+#[test]
+fn single_thread_from_env() {
+    env::set_var("ASYNC_STD_THREAD_COUNT", "1");
+
+    let threads = task::block_on(async {
+        let handles: Vec<_> = (0..5000)
+            .map(|_| task::spawn(async { thread::current().id() }))
+            .collect();
+
+        let mut threads = HashSet::new();
+        for handle in handles {
+            threads.insert(handle.await);
+        }
+        threads
+    });
+
+    assert_eq!(threads.len(), 1);
+}
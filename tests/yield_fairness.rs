@@ -0,0 +1,44 @@
+use std::env;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_std::task;
+
+/// Mirrors `SLOT_LIMIT` in `find_runnable` (src/task/executor/pool.rs); keep the two in sync.
+const SLOT_LIMIT: usize = 16;
+
+/// How many tasks are queued behind the yielding task.
+const QUEUED: usize = 8;
+
+// This is the only test in this file because the executor reads the thread count once, when the
+// first task is spawned. A single worker keeps other threads from stealing the queued tasks.
+#[test]
+fn yielding_task_does_not_starve_local_queue() {
+    env::set_var("ASYNC_STD_THREAD_COUNT", "1");
+
+    let yields = task::block_on(task::spawn(async {
+        let done = Arc::new(AtomicUsize::new(0));
+
+        // Spawned from a worker thread, these land in the local queue behind this task.
+        for _ in 0..QUEUED {
+            let done = done.clone();
+            task::spawn(async move {
+                done.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        // Every yield puts this task back into the slot, ahead of the queued tasks.
+        let mut yields = 0;
+        while done.load(Ordering::SeqCst) < QUEUED && yields < 100 * SLOT_LIMIT {
+            task::yield_now().await;
+            yields += 1;
+        }
+        yields
+    }));
+
+    assert!(
+        yields <= 2 * SLOT_LIMIT,
+        "queued tasks finished only after {} yields",
+        yields
+    );
+}